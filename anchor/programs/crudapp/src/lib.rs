@@ -15,14 +15,43 @@ pub mod crudapp {
         ctx: Context<CreatelEntry>,
         title: String,
         message: String,
+        tip_lamports: u64,
     ) -> Result<()> {
         // This is the function that handles creating a new journal entry.
         // It takes the execution context (`ctx`), a `title` string, and a `message` string as input,
         // and returns a `Result` type, where an empty `Ok(())` indicates success.
+        // A nonzero `tip_lamports` is also transferred from `owner` to `recipient`.
+        require!(title.len() <= 50, JournalError::TitleTooLong);
+        require!(message.len() <= 1000, JournalError::MessageTooLong);
+
+        if tip_lamports > 0 {
+            // Optional donation/monetization hook: move the tip from the
+            // owner to the recipient via a System Program CPI.
+            let cpi_accounts = anchor_lang::system_program::Transfer {
+                from: ctx.accounts.owner.to_account_info(),
+                to: ctx.accounts.recipient.to_account_info(),
+            };
+            let cpi_context =
+                CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+            anchor_lang::system_program::transfer(cpi_context, tip_lamports)?;
+        }
+
         let journal_entry = &mut ctx.accounts.journal_entry;
         journal_entry.owner = *ctx.accounts.owner.key;
         journal_entry.title = title;
         journal_entry.message = message;
+        journal_entry.bump = ctx.bumps.journal_entry;
+        // Storing the canonical bump here means later instructions can verify
+        // against it instead of paying for `find_program_address` again.
+
+        let now = Clock::get()?.unix_timestamp;
+        journal_entry.created_at = now;
+        journal_entry.updated_at = now;
+
+        emit!(JournalCreated {
+            owner: journal_entry.owner,
+            title: journal_entry.title.clone(),
+        });
 
         Ok(())
         // Return success. The `Ok(())` result type indicates that the function executed successfully.
@@ -30,15 +59,32 @@ pub mod crudapp {
 
     pub fn update_journal_entry(
         ctx: Context<UpdatelEntry>,
-        _title: String,
+        title: String,
         message: String,
     ) -> Result<()> {
+        require!(title.len() <= 50, JournalError::TitleTooLong);
+        require!(message.len() <= 1000, JournalError::MessageTooLong);
+
         let journal_entry = &mut ctx.accounts.journal_entry;
         journal_entry.message = message;
+        journal_entry.updated_at = Clock::get()?.unix_timestamp;
+
+        emit!(JournalUpdated {
+            owner: journal_entry.owner,
+            title: journal_entry.title.clone(),
+        });
+
         Ok(())
     }
 
-    pub fn delete_journal_entry(_ctx: Context<UpdatelEntry>) -> Result<()> {
+    pub fn delete_journal_entry(ctx: Context<DeletelEntry>) -> Result<()> {
+        // `close = owner` on `DeletelEntry` does the heavy lifting here: Anchor
+        // zeroes the account data and transfers the reclaimed rent back to `owner`.
+        emit!(JournalDeleted {
+            owner: ctx.accounts.journal_entry.owner,
+            title: ctx.accounts.journal_entry.title.clone(),
+        });
+
         Ok(())
     }
 }
@@ -69,6 +115,11 @@ pub struct CreatelEntry<'info> {
     // The `mut` keyword means the `owner` account can be modified (such as deducting rent fees for account creation).
     pub owner: Signer<'info>,
 
+    // Destination for the optional tip. Always required; the transfer to it is
+    // skipped when `tip_lamports == 0`.
+    #[account(mut)]
+    pub recipient: SystemAccount<'info>,
+
     pub system_program: Program<'info, System>,
     // The Solana system program is required for account initialization.
     // It provides system-level operations like account creation and token transfers.
@@ -80,7 +131,8 @@ pub struct UpdatelEntry<'info> {
     #[account(
     mut,
     seeds = [title.as_bytes(), owner.key().as_ref()],
-    bump,
+    bump = journal_entry.bump,
+    has_one = owner @ JournalError::Unauthorized,
     realloc = 8 + JournalEntryState::INIT_SPACE,
     realloc::payer = owner,
     realloc::zero = true,
@@ -100,7 +152,8 @@ pub struct DeletelEntry<'info> {
     #[account(
     mut,
     seeds = [title.as_bytes(), owner.key().as_ref()],
-    bump,
+    bump = journal_entry.bump,
+    has_one = owner @ JournalError::Unauthorized,
     close = owner,
     )]
     pub journal_entry: Account<'info, JournalEntryState>,
@@ -124,4 +177,39 @@ pub struct JournalEntryState {
     pub title: String,
     #[max_len(1000)]
     pub message: String,
+    pub bump: u8,
+    // The canonical bump for this PDA, recorded at creation time so later
+    // instructions can validate against it instead of re-deriving it.
+    pub created_at: i64,
+    pub updated_at: i64,
+    // Unix timestamps from the Clock sysvar, so clients can sort/display
+    // entry history without inferring it from transaction metadata.
+}
+
+#[event]
+pub struct JournalCreated {
+    pub owner: Pubkey,
+    pub title: String,
+}
+
+#[event]
+pub struct JournalUpdated {
+    pub owner: Pubkey,
+    pub title: String,
+}
+
+#[event]
+pub struct JournalDeleted {
+    pub owner: Pubkey,
+    pub title: String,
+}
+
+#[error_code]
+pub enum JournalError {
+    #[msg("Only the owner of this journal entry can perform this action.")]
+    Unauthorized,
+    #[msg("Title must be 50 bytes or less.")]
+    TitleTooLong,
+    #[msg("Message must be 1000 bytes or less.")]
+    MessageTooLong,
 }